@@ -1,4 +1,4 @@
-use memchunk::{DEFAULT_DELIMITERS, DEFAULT_TARGET_SIZE, OwnedChunker};
+use memchunk::{ChunkStats, DEFAULT_DELIMITERS, DEFAULT_TARGET_SIZE, OwnedChunker};
 use wasm_bindgen::prelude::*;
 
 /// Chunker splits text at delimiter boundaries.
@@ -31,12 +31,16 @@ impl Chunker {
     /// @param size - Target chunk size in bytes (default: 4096)
     /// @param delimiters - Delimiter characters as string (default: "\n.?")
     /// @param prefix - Put delimiter at start of next chunk (default: false)
+    /// @param boundarySafe - Back up hard splits to the nearest UTF-8 character boundary (default: false)
+    /// @param graphemeSafe - Like boundarySafe, but also avoids splitting grapheme clusters (default: false)
     #[wasm_bindgen(constructor)]
     pub fn new(
         text: &[u8],
         size: Option<usize>,
         delimiters: Option<String>,
         prefix: Option<bool>,
+        boundary_safe: Option<bool>,
+        grapheme_safe: Option<bool>,
     ) -> Chunker {
         let target_size = size.unwrap_or(DEFAULT_TARGET_SIZE);
         let delims = delimiters
@@ -48,6 +52,11 @@ impl Chunker {
         if prefix.unwrap_or(false) {
             inner = inner.prefix();
         }
+        if grapheme_safe.unwrap_or(false) {
+            inner = inner.grapheme_safe();
+        } else if boundary_safe.unwrap_or(false) {
+            inner = inner.boundary_safe();
+        }
         Chunker { inner }
     }
 
@@ -57,12 +66,16 @@ impl Chunker {
     /// @param size - Target chunk size in bytes
     /// @param pattern - Multi-byte pattern to split on (as Uint8Array)
     /// @param prefix - Put pattern at start of next chunk (default: false)
+    /// @param boundarySafe - Back up hard splits to the nearest UTF-8 character boundary (default: false)
+    /// @param graphemeSafe - Like boundarySafe, but also avoids splitting grapheme clusters (default: false)
     #[wasm_bindgen]
     pub fn with_pattern(
         text: &[u8],
         size: usize,
         pattern: &[u8],
         prefix: Option<bool>,
+        boundary_safe: Option<bool>,
+        grapheme_safe: Option<bool>,
     ) -> Chunker {
         let mut inner = OwnedChunker::new(text.to_vec())
             .size(size)
@@ -70,6 +83,11 @@ impl Chunker {
         if prefix.unwrap_or(false) {
             inner = inner.prefix();
         }
+        if grapheme_safe.unwrap_or(false) {
+            inner = inner.grapheme_safe();
+        } else if boundary_safe.unwrap_or(false) {
+            inner = inner.boundary_safe();
+        }
         Chunker { inner }
     }
 
@@ -95,6 +113,41 @@ impl Chunker {
             .flat_map(|(start, end)| [start, end])
             .collect()
     }
+
+    /// Run the chunker to completion and return aggregate size statistics
+    /// instead of the chunks themselves. See [`Stats`].
+    #[wasm_bindgen]
+    pub fn analyze(&mut self) -> Stats {
+        self.inner.analyze().into()
+    }
+}
+
+/// Aggregate chunk-size statistics, as returned by `Chunker.analyze()` /
+/// `analyze_stats()`. Mirrors `memchunk::ChunkStats` field-for-field.
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub struct Stats {
+    pub count: usize,
+    pub total_bytes: usize,
+    pub avg_size: f64,
+    pub stddev: f64,
+    pub min_size: usize,
+    pub max_size: usize,
+    pub dedup_ratio: f64,
+}
+
+impl From<ChunkStats> for Stats {
+    fn from(s: ChunkStats) -> Self {
+        Stats {
+            count: s.count,
+            total_bytes: s.total_bytes,
+            avg_size: s.avg_size,
+            stddev: s.stddev,
+            min_size: s.min_size,
+            max_size: s.max_size,
+            dedup_ratio: s.dedup_ratio,
+        }
+    }
 }
 
 /// Get the default target size (4096 bytes).
@@ -127,6 +180,8 @@ pub fn chunk_offsets(
     size: Option<usize>,
     delimiters: Option<String>,
     prefix: Option<bool>,
+    boundary_safe: Option<bool>,
+    grapheme_safe: Option<bool>,
 ) -> Vec<usize> {
     let target_size = size.unwrap_or(DEFAULT_TARGET_SIZE);
     let delims = delimiters
@@ -138,6 +193,11 @@ pub fn chunk_offsets(
     if prefix.unwrap_or(false) {
         chunker = chunker.prefix();
     }
+    if grapheme_safe.unwrap_or(false) {
+        chunker = chunker.grapheme_safe();
+    } else if boundary_safe.unwrap_or(false) {
+        chunker = chunker.boundary_safe();
+    }
     chunker
         .collect_offsets()
         .into_iter()
@@ -160,6 +220,8 @@ pub fn chunk_offsets_pattern(
     size: usize,
     pattern: &[u8],
     prefix: Option<bool>,
+    boundary_safe: Option<bool>,
+    grapheme_safe: Option<bool>,
 ) -> Vec<usize> {
     let mut chunker = OwnedChunker::new(text.to_vec())
         .size(size)
@@ -167,9 +229,103 @@ pub fn chunk_offsets_pattern(
     if prefix.unwrap_or(false) {
         chunker = chunker.prefix();
     }
+    if grapheme_safe.unwrap_or(false) {
+        chunker = chunker.grapheme_safe();
+    } else if boundary_safe.unwrap_or(false) {
+        chunker = chunker.boundary_safe();
+    }
     chunker
         .collect_offsets()
         .into_iter()
         .flat_map(|(start, end)| [start, end])
         .collect()
 }
+
+/// FastCDC content-defined chunking that returns offsets in a single call.
+/// Returns a flat array [start1, end1, start2, end2, ...].
+///
+/// Cut points are chosen by content (a rolling gear hash) instead of by
+/// delimiter, so identical bytes produce the same cut point regardless of
+/// where they land in the stream — useful for dedup/backup workloads where
+/// fixed-window chunking gives near-zero dedup benefit.
+///
+/// @example
+/// ```javascript
+/// const offsets = chunk_offsets_fastcdc(textBytes, 2048, 8192, 65536);
+/// ```
+#[wasm_bindgen]
+pub fn chunk_offsets_fastcdc(text: &[u8], min: usize, avg: usize, max: usize) -> Vec<usize> {
+    OwnedChunker::new(text.to_vec())
+        .cdc_fastcdc(min, avg, max)
+        .collect_offsets()
+        .into_iter()
+        .flat_map(|(start, end)| [start, end])
+        .collect()
+}
+
+/// AE (Asymmetric Extremum) content-defined chunking that returns offsets
+/// in a single call. Returns a flat array [start1, end1, start2, end2, ...].
+///
+/// Cheaper than `chunk_offsets_fastcdc` — a single comparison per byte, no
+/// hash table — for users who want maximum throughput. `max_size` caps the
+/// chunk length so pathological input still terminates.
+///
+/// @example
+/// ```javascript
+/// const offsets = chunk_offsets_ae(textBytes, 32, 65536);
+/// ```
+#[wasm_bindgen]
+pub fn chunk_offsets_ae(text: &[u8], window: usize, max_size: usize) -> Vec<usize> {
+    OwnedChunker::new(text.to_vec())
+        .size(max_size)
+        .cdc_ae(window)
+        .collect_offsets()
+        .into_iter()
+        .flat_map(|(start, end)| [start, end])
+        .collect()
+}
+
+/// Rabin-fingerprint content-defined chunking that returns offsets in a
+/// single call. Returns a flat array [start1, end1, start2, end2, ...].
+///
+/// Boundaries are chosen with a polynomial rolling hash rather than the
+/// gear hash behind `chunk_offsets_fastcdc`, matching the de-facto standard
+/// used by many backup/sync tools — pick this for interoperability with
+/// existing CDC tooling rather than for raw speed.
+///
+/// @example
+/// ```javascript
+/// const offsets = chunk_offsets_rabin(textBytes, 2048, 8192, 65536);
+/// ```
+#[wasm_bindgen]
+pub fn chunk_offsets_rabin(text: &[u8], min: usize, avg: usize, max: usize) -> Vec<usize> {
+    OwnedChunker::new(text.to_vec())
+        .cdc_rabin(min, avg, max)
+        .collect_offsets()
+        .into_iter()
+        .flat_map(|(start, end)| [start, end])
+        .collect()
+}
+
+/// Chunk-size statistics for delimiter-based chunking, in a single call —
+/// lets JS callers pick a `size` without re-implementing the
+/// count/average/stddev/dedup math themselves. The CDC modes are not
+/// covered: no statistics call takes CDC parameters. See [`Stats`].
+///
+/// @example
+/// ```javascript
+/// const stats = analyze_stats(textBytes, 4096, ".\n?");
+/// console.log(`${stats.count} chunks, avg ${stats.avg_size} bytes, ${stats.dedup_ratio * 100}% dedup savings`);
+/// ```
+#[wasm_bindgen]
+pub fn analyze_stats(text: &[u8], size: Option<usize>, delimiters: Option<String>) -> Stats {
+    let target_size = size.unwrap_or(DEFAULT_TARGET_SIZE);
+    let delims = delimiters
+        .map(|s| s.into_bytes())
+        .unwrap_or_else(|| DEFAULT_DELIMITERS.to_vec());
+    OwnedChunker::new(text.to_vec())
+        .size(target_size)
+        .delimiters(delims)
+        .analyze()
+        .into()
+}
@@ -12,8 +12,13 @@
 //!
 //! // With custom size and delimiters
 //! let chunks: Vec<&[u8]> = chunk(text).size(1024).delimiters(b"\n.?!").collect();
+//!
+//! // Content-defined chunking for dedup-friendly output
+//! let chunks: Vec<&[u8]> = chunk(text).cdc_fastcdc(64, 256, 1024).collect();
 //! ```
 
+use std::collections::{HashSet, VecDeque};
+
 /// Default chunk target size (4KB).
 pub const DEFAULT_TARGET_SIZE: usize = 4096;
 
@@ -51,9 +56,476 @@ pub fn chunk(text: &[u8]) -> Chunker<'_> {
     Chunker::new(text)
 }
 
+/// Content-defined chunking strategy, selected via `.cdc_*()` builder methods.
+///
+/// Mutually exclusive with delimiter-based splitting: once set, it takes over
+/// boundary selection in `Iterator::next`.
+enum CdcMode {
+    /// Gear-hash normalized chunking (see `cdc_fastcdc`).
+    FastCdc {
+        min: usize,
+        avg: usize,
+        max: usize,
+        mask_s: u64,
+        mask_l: u64,
+    },
+    /// Asymmetric Extremum chunking (see `cdc_ae`).
+    Ae { window: usize },
+    /// Rabin fingerprint chunking (see `cdc_rabin`).
+    Rabin {
+        min: usize,
+        max: usize,
+        bits: u32,
+    },
+}
+
+/// Precomputed gear-hash table used by the content-defined chunking modes.
+///
+/// Each entry is a fixed pseudo-random 64-bit constant; `fastcdc_cut` mixes
+/// them into a rolling fingerprint as it scans, one multiply-free shift-add
+/// per byte.
+static GEAR: [u64; 256] = gear_table();
+
+const fn gear_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut i = 0usize;
+    while i < 256 {
+        // splitmix64 mix, just used here to fill the table with well-distributed constants.
+        let mut z = (i as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        table[i] = z ^ (z >> 31);
+        i += 1;
+    }
+    table
+}
+
+/// Derive the two normalized-chunking masks from a target average chunk size.
+///
+/// `mask_s` has more 1 bits than `mask_l`, so it's less likely to match —
+/// used while we're below `avg` to discourage early cuts, while `mask_l`
+/// is used above `avg` to encourage a cut before `max` is reached.
+fn fastcdc_masks(avg: usize) -> (u64, u64) {
+    let bits = (avg.max(2) as f64).log2().round().clamp(4.0, 62.0) as u32;
+    (mask_ones(bits + 2), mask_ones(bits.saturating_sub(2).max(1)))
+}
+
+fn mask_ones(bits: u32) -> u64 {
+    if bits >= 64 {
+        u64::MAX
+    } else {
+        (1u64 << bits) - 1
+    }
+}
+
+/// Find the next FastCDC cut point in `data`, returning its length.
+///
+/// Feeds every byte into the rolling gear-hash fingerprint, but only checks
+/// for a cut once `min` bytes have been consumed: `mask_s` applies in
+/// `[min, avg)`, `mask_l` in `[avg, max)`, and a cut is forced at `max` (or
+/// at the end of `data`, if shorter) if none was found. Always returns at
+/// least 1 for non-empty `data` — a degenerate `max` (e.g. 0) must not stall
+/// the caller's iteration.
+fn fastcdc_cut(data: &[u8], min: usize, avg: usize, max: usize, mask_s: u64, mask_l: u64) -> usize {
+    if data.is_empty() {
+        return 0;
+    }
+    let max = max.min(data.len()).max(1);
+    if max <= min {
+        return max;
+    }
+
+    let mut fp: u64 = 0;
+    for (i, &byte) in data[..max].iter().enumerate() {
+        fp = (fp << 1).wrapping_add(GEAR[byte as usize]);
+        if i < min {
+            continue;
+        }
+        let mask = if i + 1 < avg { mask_s } else { mask_l };
+        if fp & mask == 0 {
+            return i + 1;
+        }
+    }
+    max
+}
+
+/// Find the next AE (Asymmetric Extremum) cut point in `data`, returning its
+/// length.
+///
+/// Tracks the largest byte seen (and its position) since the last cut; once
+/// a byte `window` positions past that extremum is reached without being
+/// exceeded, that's the cut. A single comparison per byte, no hash table —
+/// `cap` (the caller's `.size()`) forces a cut for inputs that never trip
+/// the extremum condition (e.g. monotonically increasing bytes). Always
+/// returns at least 1 for non-empty `data` — a degenerate `cap` (e.g. 0)
+/// must not stall the caller's iteration.
+fn ae_cut(data: &[u8], window: usize, cap: usize) -> usize {
+    if data.is_empty() {
+        return 0;
+    }
+    let cap = cap.min(data.len()).max(1);
+
+    let mut max_val = data[0];
+    let mut max_pos = 0usize;
+    for (i, &v) in data[..cap].iter().enumerate().skip(1) {
+        if v > max_val {
+            max_val = v;
+            max_pos = i;
+        } else if i == max_pos + window {
+            return i + 1;
+        }
+    }
+    cap
+}
+
+/// Irreducible polynomial over GF(2) used by the Rabin rolling hash, in the
+/// style of the polynomials used by the restic/rsync family of
+/// content-defined chunkers. Degree 53, with the leading `x^53` term stored
+/// explicitly as bit 53, so fingerprints fit in the low 53 bits.
+const RABIN_POLY: u64 = 0x003D_A335_8B4D_C173;
+
+/// Number of trailing bytes the Rabin rolling hash fingerprints at a time.
+const RABIN_WINDOW: usize = 64;
+
+/// Multiply two GF(2) polynomials and reduce the product modulo `RABIN_POLY`.
+const fn gf2_mulmod(mut a: u64, mut b: u64) -> u64 {
+    let mut result = 0u64;
+    while b != 0 {
+        if b & 1 != 0 {
+            result ^= a;
+        }
+        let overflow = a & (1 << 52) != 0;
+        a <<= 1;
+        if overflow {
+            a ^= RABIN_POLY;
+        }
+        b >>= 1;
+    }
+    result
+}
+
+/// `base^exp mod RABIN_POLY`, by repeated squaring.
+const fn gf2_pow(base: u64, mut exp: u32) -> u64 {
+    let mut result = 1u64;
+    let mut base = base;
+    while exp > 0 {
+        if exp & 1 != 0 {
+            result = gf2_mulmod(result, base);
+        }
+        base = gf2_mulmod(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+const fn rabin_mod_table() -> [u64; 256] {
+    let shift = gf2_pow(2, 8 * RABIN_WINDOW as u32); // x^(8*RABIN_WINDOW) mod P
+    let mut table = [0u64; 256];
+    let mut b = 0usize;
+    while b < 256 {
+        table[b] = gf2_mulmod(b as u64, shift);
+        b += 1;
+    }
+    table
+}
+
+/// `mod_table[b]`: the contribution removed from the Rabin fingerprint once
+/// byte `b` slides out of the trailing `RABIN_WINDOW`-byte window.
+static RABIN_MOD_TABLE: [u64; 256] = rabin_mod_table();
+
+/// Derive the Rabin cut mask's bit width from a target average chunk size.
+fn rabin_bits(avg: usize) -> u32 {
+    (avg.max(2) as f64).log2().round().clamp(1.0, 63.0) as u32
+}
+
+/// Find the next Rabin-fingerprint cut point in `data`, returning its
+/// length. `window` is reused scratch space holding the trailing
+/// `RABIN_WINDOW` bytes, so repeated calls don't reallocate. Always returns
+/// at least 1 for non-empty `data` — a degenerate `max` (e.g. 0) must not
+/// stall the caller's iteration.
+fn rabin_cut(
+    data: &[u8],
+    min: usize,
+    max: usize,
+    bits: u32,
+    window: &mut VecDeque<u8>,
+) -> usize {
+    window.clear();
+    if data.is_empty() {
+        return 0;
+    }
+    let max = max.min(data.len()).max(1);
+    if max <= min {
+        return max;
+    }
+
+    let mask = mask_ones(bits);
+    let mut fp: u64 = 0;
+    for (i, &byte) in data[..max].iter().enumerate() {
+        let out_byte = if window.len() == RABIN_WINDOW {
+            window.pop_front().unwrap()
+        } else {
+            0
+        };
+        window.push_back(byte);
+
+        // fp * x^8 + byte, with the byte leaving the window un-mixed back out.
+        fp = gf2_mulmod(fp, 0x100) ^ (byte as u64) ^ RABIN_MOD_TABLE[out_byte as usize];
+
+        if i + 1 > min && fp & mask == 0 {
+            return i + 1;
+        }
+    }
+    max
+}
+
+/// Find the last UTF-8 codepoint start at or before the end of `window`.
+///
+/// Walks backward while the top two bits read `10` (a continuation byte).
+/// Returns `None` if the whole window is continuation bytes — i.e. the
+/// codepoint they belong to started before the window, so there's no safe
+/// boundary inside it at all.
+fn last_char_boundary(window: &[u8]) -> Option<usize> {
+    let mut i = window.len();
+    while i > 0 {
+        i -= 1;
+        if window[i] & 0b1100_0000 != 0b1000_0000 {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Decode the UTF-8 codepoint starting at `pos`, returning `(codepoint, byte_len)`.
+fn decode_char_at(window: &[u8], pos: usize) -> Option<(u32, usize)> {
+    let b0 = *window.get(pos)?;
+    if b0 & 0x80 == 0 {
+        Some((b0 as u32, 1))
+    } else if b0 & 0xE0 == 0xC0 {
+        let b1 = *window.get(pos + 1)?;
+        Some((((b0 & 0x1F) as u32) << 6 | (b1 & 0x3F) as u32, 2))
+    } else if b0 & 0xF0 == 0xE0 {
+        let b1 = *window.get(pos + 1)?;
+        let b2 = *window.get(pos + 2)?;
+        Some((
+            ((b0 & 0x0F) as u32) << 12 | ((b1 & 0x3F) as u32) << 6 | (b2 & 0x3F) as u32,
+            3,
+        ))
+    } else if b0 & 0xF8 == 0xF0 {
+        let b1 = *window.get(pos + 1)?;
+        let b2 = *window.get(pos + 2)?;
+        let b3 = *window.get(pos + 3)?;
+        Some((
+            ((b0 & 0x07) as u32) << 18
+                | ((b1 & 0x3F) as u32) << 12
+                | ((b2 & 0x3F) as u32) << 6
+                | (b3 & 0x3F) as u32,
+            4,
+        ))
+    } else {
+        None
+    }
+}
+
+/// Grapheme-cluster break category used by `.grapheme_safe()`.
+///
+/// Not a full UAX #29 implementation — covers the categories most likely to
+/// split visibly wrong in practice: combining marks, zero-width joiners,
+/// prepend characters, and regional indicators (flag emoji pairs).
+#[derive(Clone, Copy, PartialEq)]
+enum GraphemeCat {
+    Extend,
+    Prepend,
+    ZwJoin,
+    RegionalIndicator,
+}
+
+/// Sorted `(low, high, category)` codepoint ranges, looked up by binary
+/// search over the codepoint value.
+static GRAPHEME_RANGES: &[(u32, u32, GraphemeCat)] = &[
+    (0x0300, 0x036F, GraphemeCat::Extend), // combining diacritical marks
+    (0x0483, 0x0489, GraphemeCat::Extend),
+    (0x0591, 0x05BD, GraphemeCat::Extend),
+    (0x0600, 0x0605, GraphemeCat::Prepend),
+    (0x0610, 0x061A, GraphemeCat::Extend),
+    (0x064B, 0x065F, GraphemeCat::Extend),
+    (0x0670, 0x0670, GraphemeCat::Extend),
+    (0x06D6, 0x06DC, GraphemeCat::Extend),
+    (0x06DD, 0x06DD, GraphemeCat::Prepend),
+    (0x0E31, 0x0E31, GraphemeCat::Extend),
+    (0x0E34, 0x0E3A, GraphemeCat::Extend),
+    (0x200D, 0x200D, GraphemeCat::ZwJoin),
+    (0xFE00, 0xFE0F, GraphemeCat::Extend), // variation selectors
+    (0x1F1E6, 0x1F1FF, GraphemeCat::RegionalIndicator),
+    (0x1F3FB, 0x1F3FF, GraphemeCat::Extend), // skin tone modifiers
+];
+
+fn grapheme_cat(cp: u32) -> Option<GraphemeCat> {
+    GRAPHEME_RANGES
+        .binary_search_by(|&(lo, hi, _)| {
+            if cp < lo {
+                std::cmp::Ordering::Greater
+            } else if cp > hi {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|idx| GRAPHEME_RANGES[idx].2)
+}
+
+/// Find the last grapheme-cluster-safe split point before `end`
+/// (relative to `pos`, i.e. within `text[pos..end]`), applying the
+/// extend/prepend/regional-indicator join rules to walk back past any
+/// boundary that would split a cluster in two.
+///
+/// Classifies candidate split characters against the full `text` rather
+/// than the `text[pos..end]` window alone, since a joining character
+/// (combining mark, ZWJ, second half of a regional-indicator pair) can have
+/// trailing bytes that fall past `end` — decoding against the truncated
+/// window would misread it as `None` and fail to detect the join.
+///
+/// Returns `None` under the same condition as [`last_char_boundary`].
+fn grapheme_safe_boundary(text: &[u8], pos: usize, end: usize) -> Option<usize> {
+    let window = &text[pos..end];
+    let mut b = last_char_boundary(window)?;
+    loop {
+        if b == 0 {
+            return Some(0);
+        }
+
+        let joins_prev = match decode_char_at(text, pos + b) {
+            Some((cp, _)) => match grapheme_cat(cp) {
+                Some(GraphemeCat::Extend) | Some(GraphemeCat::ZwJoin) => true,
+                Some(GraphemeCat::RegionalIndicator) => last_char_boundary(&window[..b])
+                    .and_then(|p| decode_char_at(text, pos + p))
+                    .is_some_and(|(pcp, _)| grapheme_cat(pcp) == Some(GraphemeCat::RegionalIndicator)),
+                _ => false,
+            },
+            None => false,
+        };
+        if joins_prev {
+            b = last_char_boundary(&window[..b])?;
+            continue;
+        }
+
+        // Don't split right after a Prepend character either — it must stay
+        // joined to what follows it.
+        if let Some(prev) = last_char_boundary(&window[..b]) {
+            if let Some((prev_cp, _)) = decode_char_at(text, pos + prev) {
+                if grapheme_cat(prev_cp) == Some(GraphemeCat::Prepend) {
+                    b = prev;
+                    continue;
+                }
+            }
+        }
+
+        return Some(b);
+    }
+}
+
+/// Walk forward from `from` to the next position in `text` where it's safe
+/// to cut: a complete codepoint boundary, and — when `grapheme_safe` — not
+/// inside a cluster either. Used as a last resort when the backward search
+/// within the window finds nothing, meaning the window's tail truncated a
+/// codepoint/cluster that started at its very first byte; the chunk has to
+/// grow past `target_size` to land somewhere safe.
+fn forward_safe_boundary(text: &[u8], from: usize, grapheme_safe: bool) -> usize {
+    let mut i = from;
+    while i < text.len() {
+        match decode_char_at(text, i) {
+            Some((cp, len)) if grapheme_safe => {
+                let prev_cat = last_char_boundary(&text[..i])
+                    .and_then(|p| decode_char_at(text, p))
+                    .and_then(|(pcp, _)| grapheme_cat(pcp));
+                let joins_prev = match grapheme_cat(cp) {
+                    Some(GraphemeCat::Extend) | Some(GraphemeCat::ZwJoin) => true,
+                    Some(GraphemeCat::RegionalIndicator) => prev_cat == Some(GraphemeCat::RegionalIndicator),
+                    _ => prev_cat == Some(GraphemeCat::Prepend),
+                };
+                if !joins_prev {
+                    return i;
+                }
+                i += len;
+            }
+            Some(_) => return i,
+            None => i += 1,
+        }
+    }
+    text.len()
+}
+
+/// Choose where to hard-split `text[pos..end]` when no delimiter was found,
+/// honoring `.boundary_safe()` / `.grapheme_safe()` if set.
+///
+/// Falls back to `end - pos` (the original fixed-size hard split) when
+/// neither flag is set. When the whole window turns out to be one oversized
+/// codepoint/cluster with no safe boundary inside it, extends forward past
+/// `end` instead of forcing an unsafe cut there.
+fn safe_hard_split(text: &[u8], pos: usize, end: usize, boundary_safe: bool, grapheme_safe: bool) -> usize {
+    if !boundary_safe && !grapheme_safe {
+        return end - pos;
+    }
+
+    // The caller only hard-splits when more than `end - pos` bytes remain, so
+    // `text[end]` exists; including it lets the split stay at `end` itself
+    // when `end` already is a safe boundary.
+    let candidate = if grapheme_safe {
+        grapheme_safe_boundary(text, pos, end + 1)
+    } else {
+        last_char_boundary(&text[pos..=end])
+    };
+
+    match candidate {
+        Some(b) if b > 0 => b,
+        _ => forward_safe_boundary(text, end, grapheme_safe) - pos,
+    }
+}
+
+/// 64-bit FNV-1a hash, used internally by [`OwnedChunker::analyze`] to
+/// estimate dedup savings without pulling in a hashing crate.
+fn fnv1a64(data: &[u8]) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let mut hash = OFFSET;
+    for &b in data {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
+/// Aggregate chunk-size statistics, returned by [`OwnedChunker::analyze`].
+///
+/// Useful for tuning `.size()` / CDC parameters without re-implementing the
+/// math: run the chunker once and get a size distribution plus a
+/// dedup-savings estimate instead of the chunks themselves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChunkStats {
+    /// Number of chunks produced.
+    pub count: usize,
+    /// Total bytes across all chunks (equals the input length).
+    pub total_bytes: usize,
+    /// Mean chunk size in bytes.
+    pub avg_size: f64,
+    /// Standard deviation of chunk sizes.
+    pub stddev: f64,
+    /// Smallest chunk size (0 if there were no chunks).
+    pub min_size: usize,
+    /// Largest chunk size (0 if there were no chunks).
+    pub max_size: usize,
+    /// Estimated fraction of `total_bytes` that would be saved by
+    /// deduplicating identical chunks — `(total_bytes - unique_bytes) /
+    /// total_bytes`. Based on a 64-bit hash of each chunk's content, so it's
+    /// an estimate (collisions are possible, though unlikely in practice).
+    pub dedup_ratio: f64,
+}
+
 /// Chunker splits text at delimiter boundaries.
 ///
-/// Created via [`chunk()`], can be configured with `.size()` and `.delimiters()`.
+/// Created via [`chunk()`], can be configured with `.size()` and `.delimiters()`,
+/// or switched to a content-defined mode such as `.cdc_fastcdc()`.
 pub struct Chunker<'a> {
     text: &'a [u8],
     target_size: usize,
@@ -61,6 +533,10 @@ pub struct Chunker<'a> {
     pos: usize,
     table: Option<[bool; 256]>,
     initialized: bool,
+    cdc: Option<CdcMode>,
+    rabin_window: VecDeque<u8>,
+    boundary_safe: bool,
+    grapheme_safe: bool,
 }
 
 impl<'a> Chunker<'a> {
@@ -72,6 +548,10 @@ impl<'a> Chunker<'a> {
             pos: 0,
             table: None,
             initialized: false,
+            cdc: None,
+            rabin_window: VecDeque::with_capacity(RABIN_WINDOW),
+            boundary_safe: false,
+            grapheme_safe: false,
         }
     }
 
@@ -87,6 +567,73 @@ impl<'a> Chunker<'a> {
         self
     }
 
+    /// Switch to FastCDC content-defined chunking.
+    ///
+    /// Cut points are chosen by content (a rolling gear hash) instead of by
+    /// delimiter, so identical bytes produce the same cut point regardless
+    /// of where they land in the stream — this is what makes the output
+    /// dedup-friendly. `min`/`avg`/`max` bound the resulting chunk sizes; a
+    /// cut is forced at `max` if none is found earlier. Overrides
+    /// `.delimiters()`.
+    pub fn cdc_fastcdc(mut self, min: usize, avg: usize, max: usize) -> Self {
+        let (mask_s, mask_l) = fastcdc_masks(avg);
+        self.cdc = Some(CdcMode::FastCdc {
+            min,
+            avg,
+            max,
+            mask_s,
+            mask_l,
+        });
+        self
+    }
+
+    /// Switch to AE (Asymmetric Extremum) content-defined chunking.
+    ///
+    /// Cheaper than `.cdc_fastcdc()` — one comparison per byte, no hash
+    /// table — at the cost of less uniform chunk sizes. Combine with
+    /// `.size()` to cap the maximum chunk length, since pathological input
+    /// (e.g. monotonically increasing bytes) never trips the extremum
+    /// condition on its own. Overrides `.delimiters()`.
+    pub fn cdc_ae(mut self, window: usize) -> Self {
+        self.cdc = Some(CdcMode::Ae { window });
+        self
+    }
+
+    /// Switch to Rabin-fingerprint content-defined chunking.
+    ///
+    /// Uses a polynomial rolling hash over a trailing window of bytes
+    /// instead of the gear hash in `.cdc_fastcdc()`, so boundaries match
+    /// the de-facto standard used by many backup/sync tools — pick this
+    /// over FastCDC/AE for interoperability, not speed. `min`/`avg`/`max`
+    /// bound the resulting chunk sizes, same as `.cdc_fastcdc()`. Overrides
+    /// `.delimiters()`.
+    pub fn cdc_rabin(mut self, min: usize, avg: usize, max: usize) -> Self {
+        self.cdc = Some(CdcMode::Rabin {
+            min,
+            max,
+            bits: rabin_bits(avg),
+        });
+        self
+    }
+
+    /// When a hard split (no delimiter found in the window) would land
+    /// mid-codepoint, back up to the nearest UTF-8 character boundary
+    /// instead. Free when no hard split occurs.
+    pub fn boundary_safe(mut self) -> Self {
+        self.boundary_safe = true;
+        self
+    }
+
+    /// Like `.boundary_safe()`, but also avoids splitting inside a grapheme
+    /// cluster (e.g. a combining-mark sequence or flag emoji pair) by
+    /// walking back to the nearest legal cluster break. Implies
+    /// `.boundary_safe()`. Free when no hard split occurs.
+    pub fn grapheme_safe(mut self) -> Self {
+        self.boundary_safe = true;
+        self.grapheme_safe = true;
+        self
+    }
+
     /// Initialize lookup table if needed (called on first iteration).
     fn init(&mut self) {
         if !self.initialized {
@@ -137,6 +684,53 @@ impl<'a> Iterator for Chunker<'a> {
 
         let remaining = self.text.len() - self.pos;
 
+        match self.cdc {
+            Some(CdcMode::FastCdc {
+                min,
+                avg,
+                max,
+                mask_s,
+                mask_l,
+            }) => {
+                if remaining <= min {
+                    let chunk = &self.text[self.pos..];
+                    self.pos = self.text.len();
+                    return Some(chunk);
+                }
+                let cut = fastcdc_cut(&self.text[self.pos..], min, avg, max, mask_s, mask_l);
+                let split_at = self.pos + cut;
+                let chunk = &self.text[self.pos..split_at];
+                self.pos = split_at;
+                return Some(chunk);
+            }
+            Some(CdcMode::Ae { window }) => {
+                let cut = ae_cut(&self.text[self.pos..], window, self.target_size);
+                let split_at = self.pos + cut;
+                let chunk = &self.text[self.pos..split_at];
+                self.pos = split_at;
+                return Some(chunk);
+            }
+            Some(CdcMode::Rabin { min, max, bits, .. }) => {
+                if remaining <= min {
+                    let chunk = &self.text[self.pos..];
+                    self.pos = self.text.len();
+                    return Some(chunk);
+                }
+                let cut = rabin_cut(
+                    &self.text[self.pos..],
+                    min,
+                    max,
+                    bits,
+                    &mut self.rabin_window,
+                );
+                let split_at = self.pos + cut;
+                let chunk = &self.text[self.pos..split_at];
+                self.pos = split_at;
+                return Some(chunk);
+            }
+            None => {}
+        }
+
         // Last chunk - return remainder
         if remaining <= self.target_size {
             let chunk = &self.text[self.pos..];
@@ -150,7 +744,9 @@ impl<'a> Iterator for Chunker<'a> {
         // Find last delimiter in window
         let split_at = match self.find_last_delimiter(window) {
             Some(pos) => self.pos + pos + 1, // Include the delimiter
-            None => end,                      // No delimiter, hard split at target
+            None => {
+                self.pos + safe_hard_split(self.text, self.pos, end, self.boundary_safe, self.grapheme_safe)
+            }
         };
 
         let chunk = &self.text[self.pos..split_at];
@@ -159,6 +755,335 @@ impl<'a> Iterator for Chunker<'a> {
     }
 }
 
+/// Owned counterpart to [`Chunker`].
+///
+/// Holds its own copy of the text instead of borrowing it, so it can cross
+/// an FFI boundary (e.g. WASM) where the caller's buffer isn't available
+/// for the lifetime of the iterator. Supports everything [`Chunker`] does,
+/// plus multi-byte pattern delimiters and `.prefix()` placement.
+pub struct OwnedChunker {
+    text: Vec<u8>,
+    target_size: usize,
+    delimiters: Vec<u8>,
+    pattern: Option<Vec<u8>>,
+    prefix: bool,
+    pos: usize,
+    table: Option<[bool; 256]>,
+    initialized: bool,
+    cdc: Option<CdcMode>,
+    rabin_window: VecDeque<u8>,
+    boundary_safe: bool,
+    grapheme_safe: bool,
+}
+
+impl OwnedChunker {
+    /// Create a new `OwnedChunker` over `text`.
+    pub fn new(text: Vec<u8>) -> Self {
+        Self {
+            text,
+            target_size: DEFAULT_TARGET_SIZE,
+            delimiters: DEFAULT_DELIMITERS.to_vec(),
+            pattern: None,
+            prefix: false,
+            pos: 0,
+            table: None,
+            initialized: false,
+            cdc: None,
+            rabin_window: VecDeque::with_capacity(RABIN_WINDOW),
+            boundary_safe: false,
+            grapheme_safe: false,
+        }
+    }
+
+    /// Set the target chunk size in bytes.
+    pub fn size(mut self, size: usize) -> Self {
+        self.target_size = size;
+        self
+    }
+
+    /// Set the delimiters to split on.
+    pub fn delimiters(mut self, delimiters: Vec<u8>) -> Self {
+        self.delimiters = delimiters;
+        self.pattern = None;
+        self.table = None;
+        self.initialized = false;
+        self
+    }
+
+    /// Split on a multi-byte pattern instead of single-byte delimiters
+    /// (e.g. the `▁` metaspace token used by SentencePiece).
+    pub fn pattern(mut self, pattern: Vec<u8>) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Place the delimiter/pattern at the start of the next chunk instead
+    /// of the end of the current one.
+    pub fn prefix(mut self) -> Self {
+        self.prefix = true;
+        self
+    }
+
+    /// Switch to FastCDC content-defined chunking. See
+    /// [`Chunker::cdc_fastcdc`].
+    pub fn cdc_fastcdc(mut self, min: usize, avg: usize, max: usize) -> Self {
+        let (mask_s, mask_l) = fastcdc_masks(avg);
+        self.cdc = Some(CdcMode::FastCdc {
+            min,
+            avg,
+            max,
+            mask_s,
+            mask_l,
+        });
+        self
+    }
+
+    /// Switch to AE (Asymmetric Extremum) content-defined chunking. See
+    /// [`Chunker::cdc_ae`].
+    pub fn cdc_ae(mut self, window: usize) -> Self {
+        self.cdc = Some(CdcMode::Ae { window });
+        self
+    }
+
+    /// Switch to Rabin-fingerprint content-defined chunking. See
+    /// [`Chunker::cdc_rabin`].
+    pub fn cdc_rabin(mut self, min: usize, avg: usize, max: usize) -> Self {
+        self.cdc = Some(CdcMode::Rabin {
+            min,
+            max,
+            bits: rabin_bits(avg),
+        });
+        self
+    }
+
+    /// See [`Chunker::boundary_safe`].
+    pub fn boundary_safe(mut self) -> Self {
+        self.boundary_safe = true;
+        self
+    }
+
+    /// See [`Chunker::grapheme_safe`].
+    pub fn grapheme_safe(mut self) -> Self {
+        self.boundary_safe = true;
+        self.grapheme_safe = true;
+        self
+    }
+
+    fn init(&mut self) {
+        if !self.initialized {
+            if self.pattern.is_none() && self.delimiters.len() > 3 {
+                let mut t = [false; 256];
+                for &b in &self.delimiters {
+                    t[b as usize] = true;
+                }
+                self.table = Some(t);
+            }
+            self.initialized = true;
+        }
+    }
+
+    #[inline]
+    fn find_last_delimiter(&self, window: &[u8]) -> Option<usize> {
+        if let Some(pattern) = &self.pattern {
+            if pattern.is_empty() || pattern.len() > window.len() {
+                None
+            } else {
+                window.windows(pattern.len()).rposition(|w| w == pattern.as_slice())
+            }
+        } else if let Some(ref table) = self.table {
+            window.iter().rposition(|&b| table[b as usize])
+        } else {
+            match self.delimiters.len() {
+                1 => memchr::memrchr(self.delimiters[0], window),
+                2 => memchr::memrchr2(self.delimiters[0], self.delimiters[1], window),
+                3 => memchr::memrchr3(
+                    self.delimiters[0],
+                    self.delimiters[1],
+                    self.delimiters[2],
+                    window,
+                ),
+                0 => None,
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    /// Compute the `(start, end)` byte range of the next chunk and advance
+    /// `pos`, without copying any bytes.
+    fn next_offset(&mut self) -> Option<(usize, usize)> {
+        self.init();
+
+        if self.pos >= self.text.len() {
+            return None;
+        }
+
+        let start = self.pos;
+        let remaining = self.text.len() - self.pos;
+
+        match self.cdc {
+            Some(CdcMode::FastCdc {
+                min,
+                avg,
+                max,
+                mask_s,
+                mask_l,
+            }) => {
+                if remaining <= min {
+                    self.pos = self.text.len();
+                    return Some((start, self.pos));
+                }
+                let cut = fastcdc_cut(&self.text[self.pos..], min, avg, max, mask_s, mask_l);
+                self.pos += cut;
+                return Some((start, self.pos));
+            }
+            Some(CdcMode::Ae { window }) => {
+                let cut = ae_cut(&self.text[self.pos..], window, self.target_size);
+                self.pos += cut;
+                return Some((start, self.pos));
+            }
+            Some(CdcMode::Rabin { min, max, bits, .. }) => {
+                if remaining <= min {
+                    self.pos = self.text.len();
+                    return Some((start, self.pos));
+                }
+                let cut = rabin_cut(
+                    &self.text[self.pos..],
+                    min,
+                    max,
+                    bits,
+                    &mut self.rabin_window,
+                );
+                self.pos += cut;
+                return Some((start, self.pos));
+            }
+            None => {}
+        }
+
+        if remaining <= self.target_size {
+            self.pos = self.text.len();
+            return Some((start, self.pos));
+        }
+
+        let match_len = self.pattern.as_ref().map_or(1, |p| p.len().max(1));
+        let end = self.pos + self.target_size;
+        let window = &self.text[self.pos..end];
+
+        // In prefix mode, every chunk after the first starts with the
+        // delimiter that began it — skip past it so we don't immediately
+        // re-match it as the split point for an empty chunk.
+        let search_offset = if self.prefix && start > 0 {
+            match_len.min(window.len())
+        } else {
+            0
+        };
+
+        let split_at = match self.find_last_delimiter(&window[search_offset..]) {
+            Some(rel) if self.prefix => self.pos + search_offset + rel,
+            Some(rel) => self.pos + search_offset + rel + match_len,
+            None => {
+                self.pos + safe_hard_split(&self.text, self.pos, end, self.boundary_safe, self.grapheme_safe)
+            }
+        };
+        self.pos = split_at;
+        Some((start, split_at))
+    }
+
+    /// Get the next chunk as an owned `Vec<u8>`, or `None` if exhausted.
+    pub fn next_chunk(&mut self) -> Option<Vec<u8>> {
+        self.next_offset().map(|(start, end)| self.text[start..end].to_vec())
+    }
+
+    /// Reset the chunker to iterate from the beginning.
+    pub fn reset(&mut self) {
+        self.pos = 0;
+    }
+
+    /// Collect every chunk's `(start, end)` byte offsets in one pass,
+    /// without allocating the chunks themselves.
+    pub fn collect_offsets(&mut self) -> Vec<(usize, usize)> {
+        self.reset();
+        let mut offsets = Vec::new();
+        while let Some(range) = self.next_offset() {
+            offsets.push(range);
+        }
+        offsets
+    }
+
+    /// Run the chunker to completion and return aggregate size statistics
+    /// instead of the chunks themselves, estimating dedup savings with the
+    /// built-in FNV-1a hash. See [`ChunkStats`] and [`Self::analyze_with`]
+    /// (to supply your own hash instead). Resets the chunker first, so the
+    /// full input is analyzed regardless of how far iteration had already
+    /// progressed.
+    pub fn analyze(&mut self) -> ChunkStats {
+        self.analyze_with(fnv1a64)
+    }
+
+    /// Like [`Self::analyze`], but estimates dedup savings with a
+    /// caller-supplied hash instead of the built-in FNV-1a — useful for
+    /// matching the hash an existing content store already dedupes by
+    /// (e.g. BLAKE3 or SHA-256 truncated to 64 bits), so the estimate lines
+    /// up with what that store would actually save.
+    pub fn analyze_with<H>(&mut self, mut hash_fn: H) -> ChunkStats
+    where
+        H: FnMut(&[u8]) -> u64,
+    {
+        let offsets = self.collect_offsets();
+        let count = offsets.len();
+        if count == 0 {
+            return ChunkStats {
+                count: 0,
+                total_bytes: 0,
+                avg_size: 0.0,
+                stddev: 0.0,
+                min_size: 0,
+                max_size: 0,
+                dedup_ratio: 0.0,
+            };
+        }
+
+        let sizes = offsets.iter().map(|&(s, e)| e - s);
+        let total_bytes: usize = sizes.clone().sum();
+        let min_size = sizes.clone().min().unwrap();
+        let max_size = sizes.clone().max().unwrap();
+
+        let avg_size = total_bytes as f64 / count as f64;
+        let variance = sizes
+            .map(|size| {
+                let d = size as f64 - avg_size;
+                d * d
+            })
+            .sum::<f64>()
+            / count as f64;
+
+        let mut seen = HashSet::with_capacity(count);
+        let mut unique_bytes = 0usize;
+        for &(s, e) in &offsets {
+            if seen.insert(hash_fn(&self.text[s..e])) {
+                unique_bytes += e - s;
+            }
+        }
+
+        ChunkStats {
+            count,
+            total_bytes,
+            avg_size,
+            stddev: variance.sqrt(),
+            min_size,
+            max_size,
+            dedup_ratio: (total_bytes - unique_bytes) as f64 / total_bytes as f64,
+        }
+    }
+}
+
+impl Iterator for OwnedChunker {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,4 +1160,295 @@ mod tests {
         let chunks: Vec<_> = chunk(text).collect();
         assert!(!chunks.is_empty());
     }
+
+    #[test]
+    fn test_owned_chunker_matches_borrowed() {
+        let text = b"Hello. World. Test.";
+        let owned: Vec<_> = OwnedChunker::new(text.to_vec())
+            .size(10)
+            .delimiters(b".".to_vec())
+            .collect();
+        let borrowed: Vec<_> = chunk(text).size(10).delimiters(b".").collect();
+        assert_eq!(owned.len(), borrowed.len());
+        for (o, b) in owned.iter().zip(borrowed.iter()) {
+            assert_eq!(o.as_slice(), *b);
+        }
+    }
+
+    #[test]
+    fn test_owned_chunker_pattern_prefix() {
+        let text = b"a\xe2\x96\x81b\xe2\x96\x81c";
+        let pattern = "\u{2581}".as_bytes().to_vec();
+        let chunks: Vec<_> = OwnedChunker::new(text.to_vec())
+            .size(4)
+            .pattern(pattern)
+            .prefix()
+            .collect();
+        assert_eq!(chunks[0], b"a");
+        assert_eq!(chunks[1], "\u{2581}b".as_bytes());
+        assert_eq!(chunks[2], "\u{2581}c".as_bytes());
+    }
+
+    #[test]
+    fn test_fastcdc_total_bytes_preserved() {
+        let text: Vec<u8> = (0..5000).map(|i| (i % 251) as u8).collect();
+        let chunks: Vec<_> = chunk(&text).cdc_fastcdc(64, 256, 1024).collect();
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+        assert!(chunks.iter().all(|c| c.len() <= 1024));
+    }
+
+    #[test]
+    fn test_fastcdc_respects_min_and_max() {
+        let text: Vec<u8> = (0..2000).map(|i| (i * 7 % 256) as u8).collect();
+        let chunks: Vec<_> = chunk(&text).cdc_fastcdc(64, 256, 512).collect();
+        for c in &chunks[..chunks.len() - 1] {
+            // every non-final chunk obeys the min/max bounds
+            assert!(c.len() >= 64 && c.len() <= 512);
+        }
+    }
+
+    #[test]
+    fn test_fastcdc_degenerate_max_still_terminates() {
+        // A degenerate `max` (e.g. 0) must not stall the iterator — every
+        // chunk still has to advance `pos` by at least 1 byte.
+        let text: Vec<u8> = (0..200).map(|i| (i % 251) as u8).collect();
+        let chunks: Vec<_> = chunk(&text).cdc_fastcdc(5, 10, 0).collect();
+        assert!(!chunks.is_empty());
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+    }
+
+    #[test]
+    fn test_fastcdc_deterministic() {
+        // Same content-defined boundaries every time, not RNG-seeded per run.
+        let text: Vec<u8> = (0..4000).map(|i| (i * 17 % 256) as u8).collect();
+        let a: Vec<_> = chunk(&text).cdc_fastcdc(64, 256, 1024).collect();
+        let b: Vec<_> = chunk(&text).cdc_fastcdc(64, 256, 1024).collect();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_ae_total_bytes_preserved() {
+        let text: Vec<u8> = (0..5000).map(|i| ((i * 13) % 251) as u8).collect();
+        let chunks: Vec<_> = chunk(&text).size(512).cdc_ae(16).collect();
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+        assert!(chunks.iter().all(|c| c.len() <= 512));
+    }
+
+    #[test]
+    fn test_ae_caps_pathological_monotonic_input() {
+        // Runs of increasing bytes never trip the extremum condition, so the
+        // `.size()` cap is what terminates chunks inside a run (only chunks
+        // spanning the wrap from 255 back to 0 end earlier).
+        let text: Vec<u8> = (0..2000).map(|i| (i % 256) as u8).collect();
+        let chunks: Vec<_> = chunk(&text).size(100).cdc_ae(16).collect();
+        assert!(chunks.iter().all(|c| c.len() <= 100));
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+    }
+
+    #[test]
+    fn test_ae_degenerate_size_still_terminates() {
+        // A degenerate `.size()` cap (0) must not stall the iterator.
+        let text: Vec<u8> = (0..200).map(|i| (i % 256) as u8).collect();
+        let chunks: Vec<_> = chunk(&text).size(0).cdc_ae(16).collect();
+        assert!(!chunks.is_empty());
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+    }
+
+    #[test]
+    fn test_rabin_total_bytes_preserved() {
+        let text: Vec<u8> = (0..6000).map(|i| ((i * 37) % 251) as u8).collect();
+        let chunks: Vec<_> = chunk(&text).cdc_rabin(64, 256, 1024).collect();
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+        assert!(chunks.iter().all(|c| c.len() <= 1024));
+    }
+
+    #[test]
+    fn test_rabin_respects_min() {
+        let text: Vec<u8> = (0..3000).map(|i| (i * 11 % 256) as u8).collect();
+        let chunks: Vec<_> = chunk(&text).cdc_rabin(64, 256, 512).collect();
+        for c in &chunks[..chunks.len() - 1] {
+            assert!(c.len() >= 64 && c.len() <= 512);
+        }
+    }
+
+    #[test]
+    fn test_rabin_degenerate_max_still_terminates() {
+        // A degenerate `max` (e.g. 0) must not stall the iterator.
+        let text: Vec<u8> = (0..200).map(|i| (i % 251) as u8).collect();
+        let chunks: Vec<_> = chunk(&text).cdc_rabin(5, 10, 0).collect();
+        assert!(!chunks.is_empty());
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+    }
+
+    #[test]
+    fn test_rabin_deterministic() {
+        let text: Vec<u8> = (0..4000).map(|i| (i * 23 % 256) as u8).collect();
+        let a: Vec<_> = chunk(&text).cdc_rabin(64, 256, 1024).collect();
+        let b: Vec<_> = chunk(&text).cdc_rabin(64, 256, 1024).collect();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_hard_split_without_safety_flags_can_break_utf8() {
+        // Documents the problem `.boundary_safe()` fixes: a plain fixed-size
+        // hard split doesn't care about codepoint boundaries.
+        let text = "ab\u{20AC}de".as_bytes();
+        let chunks: Vec<_> = chunk(text).size(4).delimiters(b"\0").collect();
+        assert!(chunks.iter().any(|c| std::str::from_utf8(c).is_err()));
+    }
+
+    #[test]
+    fn test_boundary_safe_avoids_mid_codepoint_split() {
+        let text = "ab\u{20AC}de".as_bytes();
+        let chunks: Vec<_> = chunk(text)
+            .size(4)
+            .delimiters(b"\0")
+            .boundary_safe()
+            .collect();
+        for c in &chunks {
+            assert!(std::str::from_utf8(c).is_ok());
+        }
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+    }
+
+    #[test]
+    fn test_boundary_safe_alone_can_separate_combining_mark() {
+        // `.boundary_safe()` only guarantees valid UTF-8 per chunk, not
+        // intact grapheme clusters — a lone combining mark is valid UTF-8
+        // on its own, just detached from the base character it modifies.
+        // size=2 ends the window inside the mark, so the split backs up to
+        // the mark's first byte.
+        let text = "e\u{0301}xyz".as_bytes();
+        let chunks: Vec<_> = chunk(text)
+            .size(2)
+            .delimiters(b"\0")
+            .boundary_safe()
+            .collect();
+        assert_eq!(chunks[0], b"e");
+        for c in &chunks {
+            assert!(std::str::from_utf8(c).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_grapheme_safe_keeps_combining_mark_attached() {
+        let text = "e\u{0301}xyz".as_bytes();
+        let chunks: Vec<_> = chunk(text)
+            .size(3)
+            .delimiters(b"\0")
+            .grapheme_safe()
+            .collect();
+        assert_eq!(chunks[0], "e\u{0301}".as_bytes());
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+    }
+
+    #[test]
+    fn test_grapheme_safe_keeps_combining_mark_attached_across_window_edge() {
+        // The combining mark's second byte falls exactly at the window's
+        // truncation point (size=2), so detecting the join requires
+        // decoding past the window's own edge.
+        let text = "e\u{0301}xyz".as_bytes();
+        let chunks: Vec<_> = chunk(text)
+            .size(2)
+            .delimiters(b"\0")
+            .grapheme_safe()
+            .collect();
+        assert_eq!(chunks[0], "e\u{0301}".as_bytes());
+        for c in &chunks {
+            assert!(std::str::from_utf8(c).is_ok());
+        }
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+    }
+
+    #[test]
+    fn test_grapheme_safe_keeps_regional_indicator_pair_attached() {
+        // U+1F1FA U+1F1F8 ("US" regional indicators, rendered as a flag) —
+        // each is 4 bytes, so a window edge can easily land mid-pair.
+        let text = "\u{1F1FA}\u{1F1F8}xyz".as_bytes();
+        let chunks: Vec<_> = chunk(text)
+            .size(5)
+            .delimiters(b"\0")
+            .grapheme_safe()
+            .collect();
+        assert_eq!(chunks[0], "\u{1F1FA}\u{1F1F8}".as_bytes());
+        for c in &chunks {
+            assert!(std::str::from_utf8(c).is_ok());
+        }
+        let total: usize = chunks.iter().map(|c| c.len()).sum();
+        assert_eq!(total, text.len());
+    }
+
+    #[test]
+    fn test_analyze_basic_stats() {
+        let text = b"Hello. World. Test.";
+        let stats = OwnedChunker::new(text.to_vec()).size(10).delimiters(b".".to_vec()).analyze();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.total_bytes, text.len());
+        assert_eq!(stats.min_size, 6);
+        assert_eq!(stats.max_size, 7);
+        assert!((stats.avg_size - text.len() as f64 / 3.0).abs() < 1e-9);
+        assert!(stats.stddev >= 0.0);
+    }
+
+    #[test]
+    fn test_analyze_empty_text() {
+        let stats = OwnedChunker::new(Vec::new()).analyze();
+        assert_eq!(stats.count, 0);
+        assert_eq!(stats.total_bytes, 0);
+        assert_eq!(stats.avg_size, 0.0);
+        assert_eq!(stats.dedup_ratio, 0.0);
+    }
+
+    #[test]
+    fn test_analyze_detects_duplicate_chunks() {
+        // Fixed-size chunking on a repeating pattern should produce
+        // identical chunks, so dedup_ratio should reflect the repetition.
+        let text: Vec<u8> = b"abcdefgh".repeat(20);
+        let stats = OwnedChunker::new(text.clone()).size(8).delimiters(b"\0".to_vec()).analyze();
+        assert_eq!(stats.count, 20);
+        assert!(stats.dedup_ratio > 0.9);
+    }
+
+    #[test]
+    fn test_analyze_detects_dedup_savings_with_repeating_cdc_content() {
+        // Content-defined chunking is exactly what makes repeated content
+        // dedup-friendly: the same 251-byte cycle keeps landing on the same
+        // cut points, so most chunks repeat.
+        let text: Vec<u8> = (0..5000).map(|i| (i % 251) as u8).collect();
+        let stats = OwnedChunker::new(text).cdc_fastcdc(64, 256, 1024).analyze();
+        assert!(stats.dedup_ratio > 0.5);
+    }
+
+    #[test]
+    fn test_analyze_no_dedup_savings_without_repetition() {
+        let text: Vec<u8> = (0..5000)
+            .map(|i| fnv1a64(&[i as u8, (i >> 8) as u8]) as u8)
+            .collect();
+        let stats = OwnedChunker::new(text).cdc_fastcdc(64, 256, 1024).analyze();
+        assert_eq!(stats.dedup_ratio, 0.0);
+    }
+
+    #[test]
+    fn test_analyze_with_custom_hash() {
+        // A caller-supplied hash (here, a trivial sum so collisions are
+        // easy to reason about) should drive the same dedup math as the
+        // built-in FNV-1a path.
+        let text: Vec<u8> = b"abcdefgh".repeat(20);
+        let stats = OwnedChunker::new(text)
+            .size(8)
+            .delimiters(b"\0".to_vec())
+            .analyze_with(|chunk| chunk.iter().map(|&b| b as u64).sum());
+        assert_eq!(stats.count, 20);
+        assert!(stats.dedup_ratio > 0.9);
+    }
 }